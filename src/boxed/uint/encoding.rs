@@ -1,7 +1,12 @@
 //! Const-friendly decoding operations for [`BoxedUint`].
 
 use super::BoxedUint;
-use crate::Limb;
+use crate::{Limb, Word};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Decoding errors for [`BoxedUint`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -65,6 +70,455 @@ impl BoxedUint {
 
         Ok(ret)
     }
+
+    /// Serialize this value as its minimal big-endian byte string, i.e. with all leading zero
+    /// bytes stripped. Zero serializes to the empty byte string.
+    fn to_be_bytes_trimmed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.limbs.len() * Limb::BYTES);
+
+        for limb in self.limbs.iter().rev() {
+            bytes.extend_from_slice(&limb.0.to_be_bytes());
+        }
+
+        let first_nonzero = bytes.iter().position(|&b| b != 0);
+        match first_nonzero {
+            Some(i) => bytes.split_off(i),
+            None => Vec::new(),
+        }
+    }
+
+    /// Parse `bytes` as a big-endian integer, tolerating a length which is not a multiple of
+    /// [`Limb::BYTES`] and leading zero bytes, unlike [`Self::from_be_slice`].
+    ///
+    /// Returns [`DecodeError::InputSize`] if the value does not fit in `bits_precision` bits.
+    fn from_be_bytes_trimmed(bytes: &[u8], bits_precision: usize) -> Result<Self, DecodeError> {
+        if bits_precision % Limb::BITS != 0 {
+            return Err(DecodeError::Precision);
+        }
+
+        let first_nonzero = bytes.iter().position(|&b| b != 0);
+        let trimmed = match first_nonzero {
+            Some(i) => &bytes[i..],
+            None => &[][..],
+        };
+
+        let byte_len = bits_precision / 8;
+        if trimmed.len() > byte_len {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut padded = vec![0u8; byte_len];
+        padded[byte_len - trimmed.len()..].copy_from_slice(trimmed);
+        Self::from_be_slice(&padded, bits_precision)
+    }
+
+    /// Encode this value using the SCALE "compact" variable-length integer encoding used by
+    /// Substrate/Polkadot codecs.
+    ///
+    /// Values under 2^6 are encoded in a single byte, values under 2^14 in two bytes, values
+    /// under 2^30 in four bytes, and larger values use a "big-integer" mode: a length prefix
+    /// byte followed by the minimal little-endian byte string.
+    ///
+    /// # Panics
+    ///
+    /// The big-integer mode's prefix byte holds the payload length minus 4 in only 6 bits, so it
+    /// can only address up to 67 bytes (536 bits) of payload. This panics if the value's minimal
+    /// big-endian representation is wider than that, e.g. for RSA-width precisions.
+    pub fn to_scale_compact(&self) -> Vec<u8> {
+        let be_bytes = self.to_be_bytes_trimmed();
+
+        if be_bytes.len() <= 4 {
+            let mut buf = [0u8; 8];
+            buf[8 - be_bytes.len()..].copy_from_slice(&be_bytes);
+            let value = u64::from_be_bytes(buf);
+
+            if value < 1 << 6 {
+                return vec![(value as u8) << 2];
+            } else if value < 1 << 14 {
+                return (((value as u16) << 2) | 0b01).to_le_bytes().to_vec();
+            } else if value < 1 << 30 {
+                return (((value as u32) << 2) | 0b10).to_le_bytes().to_vec();
+            }
+        }
+
+        let mut le_bytes = be_bytes;
+        le_bytes.reverse();
+        let n = le_bytes.len().max(4);
+        le_bytes.resize(n, 0);
+
+        assert!(
+            n - 4 <= 0x3f,
+            "value too wide for SCALE compact big-integer encoding (max {} bytes)",
+            4 + 0x3f
+        );
+
+        let mut ret = Vec::with_capacity(1 + n);
+        ret.push((((n - 4) as u8) << 2) | 0b11);
+        ret.extend_from_slice(&le_bytes);
+        ret
+    }
+
+    /// Decode a value encoded with [`Self::to_scale_compact`], returning the decoded value
+    /// along with the number of bytes consumed from the front of `bytes`.
+    pub fn from_scale_compact(
+        bytes: &[u8],
+        bits_precision: usize,
+    ) -> Result<(Self, usize), DecodeError> {
+        let &prefix = bytes.first().ok_or(DecodeError::InputSize)?;
+
+        match prefix & 0b11 {
+            0b00 => {
+                let value = u64::from(prefix >> 2);
+                let ret = Self::from_be_bytes_trimmed(&value.to_be_bytes(), bits_precision)?;
+                Ok((ret, 1))
+            }
+            0b01 => {
+                let raw: [u8; 2] = bytes.get(..2).ok_or(DecodeError::InputSize)?.try_into().unwrap();
+                let value = u64::from(u16::from_le_bytes(raw) >> 2);
+                let ret = Self::from_be_bytes_trimmed(&value.to_be_bytes(), bits_precision)?;
+                Ok((ret, 2))
+            }
+            0b10 => {
+                let raw: [u8; 4] = bytes.get(..4).ok_or(DecodeError::InputSize)?.try_into().unwrap();
+                let value = u64::from(u32::from_le_bytes(raw) >> 2);
+                let ret = Self::from_be_bytes_trimmed(&value.to_be_bytes(), bits_precision)?;
+                Ok((ret, 4))
+            }
+            _ => {
+                let n = ((prefix >> 2) as usize) + 4;
+                let payload = bytes.get(1..1 + n).ok_or(DecodeError::InputSize)?;
+                let mut be_bytes = payload.to_vec();
+                be_bytes.reverse();
+                let ret = Self::from_be_bytes_trimmed(&be_bytes, bits_precision)?;
+                Ok((ret, 1 + n))
+            }
+        }
+    }
+
+    /// Encode this value as an RLP (Ethereum Recursive Length Prefix) scalar.
+    ///
+    /// An integer is encoded as its minimal big-endian byte string (so zero encodes as the
+    /// empty string): a single byte in `0x00..=0x7f` is emitted verbatim, a string of up to 55
+    /// bytes is prefixed with `0x80 + len`, and longer strings are prefixed with `0xb7 +
+    /// len_of_len` followed by the big-endian length.
+    pub fn to_rlp(&self) -> Vec<u8> {
+        let bytes = self.to_be_bytes_trimmed();
+
+        if bytes.len() == 1 && bytes[0] < 0x80 {
+            return bytes;
+        }
+
+        if bytes.len() <= 55 {
+            let mut ret = Vec::with_capacity(1 + bytes.len());
+            ret.push(0x80 + bytes.len() as u8);
+            ret.extend_from_slice(&bytes);
+            return ret;
+        }
+
+        let len_bytes = bytes.len().to_be_bytes();
+        let len_bytes = match len_bytes.iter().position(|&b| b != 0) {
+            Some(i) => &len_bytes[i..],
+            None => &len_bytes[len_bytes.len() - 1..],
+        };
+
+        let mut ret = Vec::with_capacity(1 + len_bytes.len() + bytes.len());
+        ret.push(0xb7 + len_bytes.len() as u8);
+        ret.extend_from_slice(len_bytes);
+        ret.extend_from_slice(&bytes);
+        ret
+    }
+
+    /// Decode a value encoded with [`Self::to_rlp`], returning the decoded value along with the
+    /// number of bytes consumed from the front of `bytes`.
+    ///
+    /// Rejects canonically-invalid encodings: a single non-zero byte `< 0x80` wrapped in a
+    /// string header, a leading zero byte in the payload, or a non-minimal length prefix.
+    pub fn from_rlp(bytes: &[u8], bits_precision: usize) -> Result<(Self, usize), DecodeError> {
+        let &lead = bytes.first().ok_or(DecodeError::InputSize)?;
+
+        match lead {
+            0x00..=0x7f => {
+                let ret = Self::from_be_bytes_trimmed(&[lead], bits_precision)?;
+                Ok((ret, 1))
+            }
+            0x80..=0xb7 => {
+                let len = (lead - 0x80) as usize;
+                let payload = bytes.get(1..1 + len).ok_or(DecodeError::InputSize)?;
+
+                if len == 1 && payload[0] < 0x80 {
+                    return Err(DecodeError::InputSize);
+                }
+                if !payload.is_empty() && payload[0] == 0 {
+                    return Err(DecodeError::InputSize);
+                }
+
+                let ret = Self::from_be_bytes_trimmed(payload, bits_precision)?;
+                Ok((ret, 1 + len))
+            }
+            0xb8..=0xbf => {
+                let len_of_len = (lead - 0xb7) as usize;
+                let len_bytes = bytes.get(1..1 + len_of_len).ok_or(DecodeError::InputSize)?;
+
+                if len_bytes[0] == 0 {
+                    return Err(DecodeError::InputSize);
+                }
+
+                let mut len_buf = [0u8; core::mem::size_of::<usize>()];
+                if len_bytes.len() > len_buf.len() {
+                    return Err(DecodeError::InputSize);
+                }
+                len_buf[len_buf.len() - len_bytes.len()..].copy_from_slice(len_bytes);
+                let len = usize::from_be_bytes(len_buf);
+
+                if len <= 55 {
+                    return Err(DecodeError::InputSize);
+                }
+
+                let payload = bytes
+                    .get(1 + len_of_len..1 + len_of_len + len)
+                    .ok_or(DecodeError::InputSize)?;
+
+                if !payload.is_empty() && payload[0] == 0 {
+                    return Err(DecodeError::InputSize);
+                }
+
+                let ret = Self::from_be_bytes_trimmed(payload, bits_precision)?;
+                Ok((ret, 1 + len_of_len + len))
+            }
+            0xc0..=0xff => Err(DecodeError::InputSize),
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BoxedUint {
+    /// Read a big-endian integer of `byte_len` bytes from a [`bytes::Buf`], advancing its
+    /// cursor, without allocating an intermediate byte buffer.
+    ///
+    /// `byte_len` need not be a multiple of [`Limb::BYTES`]: a common fixed width like 20 bytes
+    /// reads into a zero-padded trailing limb. Returns [`DecodeError::InputSize`] if `byte_len`
+    /// does not fit within `bits_precision`, or if `buf` does not hold `byte_len` bytes.
+    pub fn get_uint_be<B: bytes::Buf>(
+        buf: &mut B,
+        byte_len: usize,
+        bits_precision: usize,
+    ) -> Result<Self, DecodeError> {
+        if bits_precision % Limb::BITS != 0 {
+            return Err(DecodeError::Precision);
+        }
+
+        if byte_len * 8 > bits_precision || buf.remaining() < byte_len {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut ret = Self::zero_with_precision(bits_precision);
+        let nlimbs = byte_len.div_ceil(Limb::BYTES);
+        let partial = byte_len % Limb::BYTES;
+
+        for (i, limb) in ret.limbs[..nlimbs].iter_mut().rev().enumerate() {
+            let chunk_len = if i == 0 && partial != 0 {
+                partial
+            } else {
+                Limb::BYTES
+            };
+
+            let mut limb_bytes = [0u8; Limb::BYTES];
+            buf.copy_to_slice(&mut limb_bytes[Limb::BYTES - chunk_len..]);
+            *limb = Limb::from_be_slice(&limb_bytes);
+        }
+
+        Ok(ret)
+    }
+
+    /// Write the big-endian representation of this value into a [`bytes::BufMut`].
+    ///
+    /// If `minimal` is set, leading zero bytes are stripped so only the minimal-width
+    /// big-endian encoding is written, as produced by [`Self::to_be_bytes_trimmed`]; otherwise
+    /// all `bits_precision` bits are written.
+    pub fn put_uint_be<B: bytes::BufMut>(&self, buf: &mut B, minimal: bool) {
+        if minimal {
+            buf.put_slice(&self.to_be_bytes_trimmed());
+            return;
+        }
+
+        for limb in self.limbs.iter().rev() {
+            buf.put_slice(&limb.0.to_be_bytes());
+        }
+    }
+}
+
+/// Canonical base64 alphabet (RFC 4648, standard encoding, `+`/`/`).
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode `bytes` as a padded standard-alphabet base64 string.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut ret = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+
+        ret.push(BASE64_ALPHABET[usize::from(b0 >> 2)] as char);
+        ret.push(BASE64_ALPHABET[usize::from(((b0 & 0x03) << 4) | (b1 >> 4))] as char);
+        ret.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[usize::from(((b1 & 0x0f) << 2) | (b2 >> 6))] as char
+        } else {
+            '='
+        });
+        ret.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[usize::from(b2 & 0x3f)] as char
+        } else {
+            '='
+        });
+    }
+
+    ret
+}
+
+/// Decode a padded standard-alphabet base64 string to bytes.
+fn base64_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    fn value(c: u8) -> Result<u8, DecodeError> {
+        match c {
+            b'A'..=b'Z' => Ok(c - b'A'),
+            b'a'..=b'z' => Ok(c - b'a' + 26),
+            b'0'..=b'9' => Ok(c - b'0' + 52),
+            b'+' => Ok(62),
+            b'/' => Ok(63),
+            _ => Err(DecodeError::InputSize),
+        }
+    }
+
+    let s = s.as_bytes();
+    if s.is_empty() {
+        return Ok(Vec::new());
+    }
+    if s.len() % 4 != 0 {
+        return Err(DecodeError::InputSize);
+    }
+
+    let mut ret = Vec::with_capacity(s.len() / 4 * 3);
+
+    for chunk in s.chunks(4) {
+        let v0 = value(chunk[0])?;
+        let v1 = value(chunk[1])?;
+        ret.push((v0 << 2) | (v1 >> 4));
+
+        if chunk[2] == b'=' {
+            if chunk[3] != b'=' {
+                return Err(DecodeError::InputSize);
+            }
+            continue;
+        }
+        let v2 = value(chunk[2])?;
+        ret.push((v1 << 4) | (v2 >> 2));
+
+        if chunk[3] == b'=' {
+            continue;
+        }
+        let v3 = value(chunk[3])?;
+        ret.push((v2 << 6) | v3);
+    }
+
+    Ok(ret)
+}
+
+impl BoxedUint {
+    /// Multiply this value in place by the single-limb `multiplier` and add the single-limb
+    /// `addend`, discarding any carry beyond the current precision.
+    ///
+    /// This is the basic primitive used to accumulate a radix-encoded string one digit at a
+    /// time.
+    fn mul_add_limb_assign(&mut self, multiplier: Limb, addend: Limb) {
+        let mut carry = addend.0 as u128;
+
+        for limb in self.limbs.iter_mut() {
+            let wide = (limb.0 as u128) * (multiplier.0 as u128) + carry;
+            *limb = Limb(wide as Word);
+            carry = wide >> Limb::BITS;
+        }
+    }
+
+    /// Divide this value by the single-limb `divisor`, returning the quotient and remainder.
+    fn div_rem_limb(&self, divisor: Limb) -> (Self, Limb) {
+        let mut quotient = Self::zero_with_precision(self.limbs.len() * Limb::BITS);
+        let mut remainder: Word = 0;
+
+        for (q, limb) in quotient.limbs.iter_mut().zip(self.limbs.iter()).rev() {
+            let wide = ((remainder as u128) << Limb::BITS) | (limb.0 as u128);
+            *q = Limb((wide / divisor.0 as u128) as Word);
+            remainder = (wide % divisor.0 as u128) as Word;
+        }
+
+        (quotient, Limb(remainder))
+    }
+
+    /// Parse `s` as an integer in the given `radix` (2..=36), wrapping at `bits_precision`.
+    ///
+    /// Digits are processed left to right, multiplying the accumulator by `radix` and adding
+    /// each digit's value in turn, reusing [`Self::mul_add_limb_assign`].
+    pub fn from_str_radix(s: &str, radix: u32, bits_precision: usize) -> Result<Self, DecodeError> {
+        if !(2..=36).contains(&radix) {
+            return Err(DecodeError::InputSize);
+        }
+        if bits_precision % Limb::BITS != 0 {
+            return Err(DecodeError::Precision);
+        }
+        if s.is_empty() {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut ret = Self::zero_with_precision(bits_precision);
+        let radix_limb = Limb(radix as Word);
+
+        for c in s.chars() {
+            let digit = c.to_digit(radix).ok_or(DecodeError::InputSize)?;
+            ret.mul_add_limb_assign(radix_limb, Limb(digit as Word));
+        }
+
+        Ok(ret)
+    }
+
+    /// Format this value as a string in the given `radix` (2..=36).
+    ///
+    /// Repeatedly divides the value by `radix`, collecting remainder digits from least- to
+    /// most-significant via [`Self::div_rem_limb`], then reverses them.
+    ///
+    /// Returns [`DecodeError::InputSize`] if `radix` is outside of `2..=36`, mirroring
+    /// [`Self::from_str_radix`].
+    pub fn to_str_radix(&self, radix: u32) -> Result<String, DecodeError> {
+        if !(2..=36).contains(&radix) {
+            return Err(DecodeError::InputSize);
+        }
+
+        if bool::from(self.is_zero()) {
+            return Ok("0".to_string());
+        }
+
+        let radix_limb = Limb(radix as Word);
+        let mut value = self.clone();
+        let mut digits = Vec::new();
+
+        while !bool::from(value.is_zero()) {
+            let (quotient, remainder) = value.div_rem_limb(radix_limb);
+            digits.push(char::from_digit(remainder.0 as u32, radix).expect("valid digit"));
+            value = quotient;
+        }
+
+        Ok(digits.into_iter().rev().collect())
+    }
+
+    /// Encode the big-endian byte representation of this value as a base64 string.
+    pub fn to_base64(&self) -> String {
+        base64_encode(&self.to_be_bytes_trimmed())
+    }
+
+    /// Decode a base64-encoded big-endian byte string into a [`BoxedUint`].
+    pub fn from_base64(s: &str, bits_precision: usize) -> Result<Self, DecodeError> {
+        let bytes = base64_decode(s)?;
+        Self::from_be_bytes_trimmed(&bytes, bits_precision)
+    }
 }
 
 #[cfg(test)]
@@ -218,4 +672,253 @@ mod tests {
             Err(DecodeError::Precision)
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn scale_compact_single_byte_mode() {
+        let n = BoxedUint::from(42u64);
+        assert_eq!(n.to_scale_compact(), vec![42 << 2]);
+
+        let (decoded, len) = BoxedUint::from_scale_compact(&[42 << 2], 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, 1);
+    }
+
+    #[test]
+    fn scale_compact_two_byte_mode() {
+        let n = BoxedUint::from(420u64);
+        let encoded = n.to_scale_compact();
+        assert_eq!(encoded.len(), 2);
+
+        let (decoded, len) = BoxedUint::from_scale_compact(&encoded, 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, 2);
+    }
+
+    #[test]
+    fn scale_compact_four_byte_mode() {
+        let n = BoxedUint::from(69_420u64);
+        let encoded = n.to_scale_compact();
+        assert_eq!(encoded.len(), 4);
+
+        let (decoded, len) = BoxedUint::from_scale_compact(&encoded, 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, 4);
+    }
+
+    #[test]
+    fn scale_compact_big_integer_mode() {
+        let n = BoxedUint::from(u128::MAX);
+        let encoded = n.to_scale_compact();
+        assert_eq!(encoded[0] & 0b11, 0b11);
+
+        let (decoded, len) = BoxedUint::from_scale_compact(&encoded, 128).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, encoded.len());
+    }
+
+    #[test]
+    fn scale_compact_rejects_oversized_value() {
+        let n = BoxedUint::from(u128::MAX);
+        let encoded = n.to_scale_compact();
+        assert_eq!(
+            BoxedUint::from_scale_compact(&encoded, 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "too wide")]
+    fn scale_compact_rejects_unencodable_width() {
+        // 576 bits = 72 bytes, which is wider than the 67-byte max the big-integer mode's
+        // 6-bit length field (payload length minus 4) can represent.
+        let n = BoxedUint::max(576);
+        n.to_scale_compact();
+    }
+
+    #[test]
+    fn rlp_zero_is_empty_string() {
+        let n = BoxedUint::zero_with_precision(64);
+        assert_eq!(n.to_rlp(), vec![0x80]);
+
+        let (decoded, len) = BoxedUint::from_rlp(&[0x80], 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, 1);
+    }
+
+    #[test]
+    fn rlp_single_byte() {
+        let n = BoxedUint::from(15u64);
+        assert_eq!(n.to_rlp(), vec![15]);
+
+        let (decoded, len) = BoxedUint::from_rlp(&[15], 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, 1);
+    }
+
+    #[test]
+    fn rlp_short_string() {
+        let n = BoxedUint::from(1024u64);
+        let encoded = n.to_rlp();
+        assert_eq!(encoded[0], 0x80 + 2);
+
+        let (decoded, len) = BoxedUint::from_rlp(&encoded, 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, encoded.len());
+    }
+
+    #[test]
+    fn rlp_long_string() {
+        let n = BoxedUint::max(512);
+        let encoded = n.to_rlp();
+        assert_eq!(encoded[0], 0xb7 + 1);
+
+        let (decoded, len) = BoxedUint::from_rlp(&encoded, 512).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(len, encoded.len());
+    }
+
+    #[test]
+    fn rlp_rejects_non_canonical_single_byte() {
+        assert_eq!(
+            BoxedUint::from_rlp(&[0x81, 0x05], 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn rlp_rejects_leading_zero_byte() {
+        assert_eq!(
+            BoxedUint::from_rlp(&[0x82, 0x00, 0x05], 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn rlp_rejects_oversized_value() {
+        let n = BoxedUint::max(512);
+        let encoded = n.to_rlp();
+        assert_eq!(
+            BoxedUint::from_rlp(&encoded, 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bytes_buf_round_trip() {
+        use bytes::Buf;
+
+        let n = BoxedUint::from(0x0011223344556677u64);
+        let mut buf = bytes::BytesMut::new();
+        n.put_uint_be(&mut buf, false);
+        assert_eq!(buf.len(), 8);
+
+        let mut reader = buf.freeze();
+        let decoded = BoxedUint::get_uint_be(&mut reader, 8, 64).unwrap();
+        assert_eq!(decoded, n);
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bytes_buf_minimal_skips_leading_zero_bytes() {
+        let n = BoxedUint::from(0x42u64);
+        let mut buf = bytes::BytesMut::new();
+        n.put_uint_be(&mut buf, true);
+        assert_eq!(&buf[..], &[0x42]);
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bytes_buf_rejects_short_buffer() {
+        let mut reader = bytes::Bytes::from_static(&[0u8; 4]);
+        assert_eq!(
+            BoxedUint::get_uint_be(&mut reader, 8, 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn bytes_buf_reads_non_limb_aligned_width() {
+        use bytes::Buf;
+
+        // 20 bytes / 160 bits, a common fixed width (e.g. an Ethereum address) that isn't a
+        // multiple of Limb::BYTES on a 64-bit build.
+        let mut input = vec![0u8; 20];
+        input[0] = 0x01;
+        input[19] = 0xff;
+
+        let mut reader = bytes::Bytes::from(input.clone());
+        let n = BoxedUint::get_uint_be(&mut reader, 20, 256).unwrap();
+        assert_eq!(reader.remaining(), 0);
+
+        let mut buf = bytes::BytesMut::new();
+        n.put_uint_be(&mut buf, true);
+        assert_eq!(&buf[..], &input[..]);
+    }
+
+    #[test]
+    fn str_radix_decimal_round_trip() {
+        let n = BoxedUint::from(1_234_567_890u64);
+        assert_eq!(n.to_str_radix(10).unwrap(), "1234567890");
+
+        let decoded = BoxedUint::from_str_radix("1234567890", 10, 64).unwrap();
+        assert_eq!(decoded, n);
+    }
+
+    #[test]
+    fn str_radix_hex_round_trip() {
+        let n = BoxedUint::from(0xdead_beefu64);
+        assert_eq!(n.to_str_radix(16).unwrap(), "deadbeef");
+
+        let decoded = BoxedUint::from_str_radix("deadbeef", 16, 64).unwrap();
+        assert_eq!(decoded, n);
+    }
+
+    #[test]
+    fn str_radix_zero() {
+        let n = BoxedUint::zero_with_precision(64);
+        assert_eq!(n.to_str_radix(10).unwrap(), "0");
+    }
+
+    #[test]
+    fn to_str_radix_rejects_invalid_radix() {
+        let n = BoxedUint::from(10u64);
+        assert_eq!(n.to_str_radix(37), Err(DecodeError::InputSize));
+    }
+
+    #[test]
+    fn str_radix_rejects_invalid_digit() {
+        assert_eq!(
+            BoxedUint::from_str_radix("12z", 10, 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn str_radix_rejects_invalid_radix() {
+        assert_eq!(
+            BoxedUint::from_str_radix("10", 37, 64),
+            Err(DecodeError::InputSize)
+        );
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        let n = BoxedUint::from(0x0011_2233_4455_6677u64);
+        let encoded = n.to_base64();
+
+        let decoded = BoxedUint::from_base64(&encoded, 64).unwrap();
+        assert_eq!(decoded, n);
+    }
+
+    #[test]
+    fn base64_zero_is_empty() {
+        let n = BoxedUint::zero_with_precision(64);
+        assert_eq!(n.to_base64(), "");
+
+        let decoded = BoxedUint::from_base64("", 64).unwrap();
+        assert_eq!(decoded, n);
+    }
+}
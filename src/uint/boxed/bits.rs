@@ -0,0 +1,111 @@
+//! Bit-level accessors for [`BoxedUint`].
+
+use super::BoxedUint;
+use crate::{Limb, Word};
+use subtle::{Choice, ConditionallySelectable};
+
+impl BoxedUint {
+    /// Get the value of the bit at position `index`, as a [`Choice`].
+    ///
+    /// Returns `Choice::from(0)` for an `index` outside of this value's `bits_precision` rather
+    /// than panicking.
+    ///
+    /// This operation is constant-time with respect to `index`.
+    pub fn bit(&self, index: u32) -> Choice {
+        let limb_index = (index / Limb::BITS as u32) as usize;
+        let bit_index = index % Limb::BITS as u32;
+
+        let mut ret = 0u8;
+        for (i, limb) in self.limbs.iter().enumerate() {
+            let bit = ((limb.0 >> bit_index) & 1) as u8;
+            ret = u8::conditional_select(&ret, &bit, Choice::from((i == limb_index) as u8));
+        }
+
+        Choice::from(ret)
+    }
+
+    /// Set the bit at position `index` to `value`.
+    ///
+    /// Has no effect if `index` is outside of this value's `bits_precision`.
+    ///
+    /// This operation is constant-time with respect to `index`.
+    pub fn set_bit(&mut self, index: u32, value: Choice) {
+        let limb_index = (index / Limb::BITS as u32) as usize;
+        let bit_index = index % Limb::BITS as u32;
+        let bit_mask: Word = 1 << bit_index;
+
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let cleared = Limb(limb.0 & !bit_mask);
+            let set = Limb(cleared.0 | bit_mask);
+            let with_bit = Limb::conditional_select(&cleared, &set, value);
+            *limb = Limb::conditional_select(limb, &with_bit, Choice::from((i == limb_index) as u8));
+        }
+    }
+
+    /// Iterate over the bits of this value from least- to most-significant, across the full
+    /// `bits_precision` of this integer.
+    ///
+    /// Each bit is yielded as a [`Choice`] via [`Self::bit`], so indexing remains constant-time.
+    pub fn iter_bits(&self) -> impl Iterator<Item = Choice> + '_ {
+        (0..(self.limbs.len() * Limb::BITS) as u32).map(move |index| self.bit(index))
+    }
+}
+
+#[cfg(feature = "bitvec")]
+impl BoxedUint {
+    /// Borrow the limbs of this value as a [`bitvec::slice::BitSlice`] for bit-level slicing
+    /// and counting.
+    pub fn as_bitslice(&self) -> &bitvec::slice::BitSlice<Word, bitvec::order::Lsb0> {
+        bitvec::slice::BitSlice::from_slice(self.as_words())
+    }
+
+    /// Mutably borrow the limbs of this value as a [`bitvec::slice::BitSlice`].
+    pub fn as_bitslice_mut(&mut self) -> &mut bitvec::slice::BitSlice<Word, bitvec::order::Lsb0> {
+        bitvec::slice::BitSlice::from_slice_mut(self.as_words_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxedUint;
+    use subtle::Choice;
+
+    #[test]
+    fn bit_get_set() {
+        let mut n = BoxedUint::zero_with_precision(128);
+        assert_eq!(bool::from(n.bit(5)), false);
+
+        n.set_bit(5, Choice::from(1));
+        assert_eq!(bool::from(n.bit(5)), true);
+
+        n.set_bit(5, Choice::from(0));
+        assert_eq!(bool::from(n.bit(5)), false);
+    }
+
+    #[test]
+    fn bit_out_of_range_is_zero() {
+        let n = BoxedUint::max(128);
+        assert_eq!(bool::from(n.bit(128)), false);
+        assert_eq!(bool::from(n.bit(1_000_000)), false);
+    }
+
+    #[test]
+    fn set_bit_out_of_range_is_noop() {
+        let mut n = BoxedUint::zero_with_precision(128);
+        n.set_bit(128, Choice::from(1));
+        assert_eq!(n, BoxedUint::zero_with_precision(128));
+    }
+
+    #[test]
+    fn iter_bits_matches_max() {
+        let n = BoxedUint::max(128);
+        assert!(n.iter_bits().all(|bit| bool::from(bit)));
+        assert_eq!(n.iter_bits().count(), 128);
+    }
+
+    #[test]
+    fn iter_bits_matches_zero() {
+        let n = BoxedUint::zero_with_precision(128);
+        assert!(n.iter_bits().all(|bit| !bool::from(bit)));
+    }
+}